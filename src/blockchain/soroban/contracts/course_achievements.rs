@@ -1,5 +1,8 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Env, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec};
+
+const TTL_THRESHOLD: u32 = 1000;
+const TTL_EXTEND_TO: u32 = 1000;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -9,6 +12,44 @@ pub struct Achievement {
     pub user_id: u32,
     pub issued_at: u64,
     pub metadata_uri: Symbol,
+    pub owner: Address,
+    pub soulbound: bool,
+    pub issuer: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Approval {
+    pub spender: Address,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Revocation {
+    pub timestamp: u64,
+    pub reason: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AchievementStatus {
+    Active,
+    Revoked,
+    Unknown,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum DataKey {
+    Admin,
+    Issuers,
+    NextId,
+    Achievement(u32),
+    UserIndex(u32),
+    CourseIndex(u32),
+    Approval(u32),
+    Revocation(u32),
 }
 
 #[contract]
@@ -16,17 +57,105 @@ pub struct CourseAchievementsContract;
 
 #[contractimpl]
 impl CourseAchievementsContract {
+    /// Initializes the contract with an admin address.
+    ///
+    /// Must be called once before any other entrypoint. The admin is
+    /// implicitly an authorized issuer and can add or remove other issuers.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `admin` - The address granted admin privileges.
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::Issuers, &Vec::<Address>::new(&env));
+        env.storage().instance().set(&DataKey::NextId, &1u32);
+        env.storage()
+            .instance()
+            .extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
+        Ok(())
+    }
+
+    /// Grants issuer privileges to `issuer`. Only callable by the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `admin` - The admin address; must authenticate and match the stored admin.
+    /// * `issuer` - The address to authorize as an issuer.
+    pub fn add_issuer(env: Env, admin: Address, issuer: Address) -> Result<(), Error> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut issuers = Self::issuers(&env);
+        if !issuers.contains(&issuer) {
+            issuers.push_back(issuer);
+        }
+        env.storage().instance().set(&DataKey::Issuers, &issuers);
+        Ok(())
+    }
+
+    /// Revokes issuer privileges from `issuer`. Only callable by the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `admin` - The admin address; must authenticate and match the stored admin.
+    /// * `issuer` - The address to remove from the issuer set.
+    pub fn remove_issuer(env: Env, admin: Address, issuer: Address) -> Result<(), Error> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let issuers = Self::issuers(&env);
+        let mut remaining = Vec::new(&env);
+        for existing in issuers.iter() {
+            if existing != issuer {
+                remaining.push_back(existing);
+            }
+        }
+        env.storage().instance().set(&DataKey::Issuers, &remaining);
+        Ok(())
+    }
+
     /// Issues a new course achievement.
     ///
+    /// `issuer` must be the admin or an authorized issuer, enforced via
+    /// `require_auth`, otherwise `Error::Unauthorized` is returned. `owner`
+    /// becomes the on-chain holder of the credential. The achievement is
+    /// stored in its own persistent entry keyed by id, and its id is
+    /// appended to the user and course secondary indexes so lookups never
+    /// require scanning the full achievement set.
+    ///
     /// # Arguments
     ///
     /// * `env` - The contract environment.
+    /// * `issuer` - The address minting the achievement.
     /// * `course_id` - The ID of the course.
     /// * `user_id` - The ID of the user receiving the achievement.
+    /// * `owner` - The address that holds the achievement.
     /// * `metadata_uri` - A URI pointing to the achievement's metadata (e.g., IPFS hash).
-    pub fn issue(env: Env, course_id: u32, user_id: u32, metadata_uri: Symbol) -> Result<Achievement, Error> {
-        let mut achievements: Vec<Achievement> = env.storage().instance().get(&symbol_short!("achievements")).unwrap_or(Vec::new(&env));
-        let id = achievements.len() as u32 + 1;
+    /// * `soulbound` - If `true`, the achievement can never be transferred.
+    pub fn issue(
+        env: Env,
+        issuer: Address,
+        course_id: u32,
+        user_id: u32,
+        owner: Address,
+        metadata_uri: Symbol,
+        soulbound: bool,
+    ) -> Result<Achievement, Error> {
+        issuer.require_auth();
+        if !Self::is_authorized_issuer(&env, &issuer) {
+            return Err(Error::Unauthorized);
+        }
+
+        let id = Self::next_id(&env);
         let issued_at = env.ledger().timestamp();
 
         let achievement = Achievement {
@@ -35,16 +164,196 @@ impl CourseAchievementsContract {
             user_id,
             issued_at,
             metadata_uri,
+            owner: owner.clone(),
+            soulbound,
+            issuer: issuer.clone(),
         };
 
-        achievements.push_back(achievement.clone());
-        env.storage().instance().set(&symbol_short!("achievements"), &achievements);
-        env.storage().instance().extend_ttl(1000, 1000);
+        Self::put_achievement(&env, &achievement);
+        Self::index_push(&env, DataKey::UserIndex(user_id), id);
+        Self::index_push(&env, DataKey::CourseIndex(course_id), id);
+
+        env.events().publish(
+            (Symbol::new(&env, "achievement"), Symbol::new(&env, "issued")),
+            (id, course_id, user_id, issued_at, achievement.metadata_uri.clone()),
+        );
 
         Ok(achievement)
     }
 
-    /// Verifies if a user has a specific achievement.
+    /// Transfers an achievement to `to`, recorded under `to_user_id`.
+    ///
+    /// The destination address is supplied directly by the caller rather
+    /// than resolved through any stored per-user mapping, so transferring
+    /// one achievement can never redirect where a different achievement's
+    /// future transfer would land.
+    ///
+    /// Fails with `Error::Unauthorized` if the achievement is soulbound, or
+    /// if `from` is neither the current owner nor an unexpired approved
+    /// spender. Fails with `Error::NotFound` if the achievement cannot be
+    /// resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `from` - The current owner or an approved spender; must authenticate.
+    /// * `to` - The address that will hold the achievement.
+    /// * `to_user_id` - The user ID the achievement is recorded under after the transfer.
+    /// * `achievement_id` - The ID of the achievement to transfer.
+    pub fn transfer(
+        env: Env,
+        from: Address,
+        to: Address,
+        to_user_id: u32,
+        achievement_id: u32,
+    ) -> Result<(), Error> {
+        from.require_auth();
+
+        let mut achievement = Self::get_achievement(&env, achievement_id)?;
+        if achievement.soulbound {
+            return Err(Error::Unauthorized);
+        }
+        if !Self::is_owner_or_approved(&env, &achievement, &from) {
+            return Err(Error::Unauthorized);
+        }
+
+        let from_user_id = achievement.user_id;
+        achievement.owner = to.clone();
+        achievement.user_id = to_user_id;
+        Self::put_achievement(&env, &achievement);
+
+        Self::index_remove(&env, DataKey::UserIndex(from_user_id), achievement_id);
+        Self::index_insert_sorted(&env, DataKey::UserIndex(to_user_id), achievement_id);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Approval(achievement_id));
+
+        env.events().publish(
+            (Symbol::new(&env, "achievement"), Symbol::new(&env, "transferred")),
+            (achievement_id, from, to_user_id),
+        );
+
+        Ok(())
+    }
+
+    /// Approves `spender` to transfer a specific achievement on `owner`'s behalf.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `owner` - The current owner of the achievement; must authenticate.
+    /// * `spender` - The address approved to transfer the achievement.
+    /// * `achievement_id` - The ID of the achievement the approval applies to.
+    /// * `expires_at` - The ledger timestamp from which on (inclusive) the approval is no longer valid.
+    pub fn approve(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        achievement_id: u32,
+        expires_at: u64,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        let achievement = Self::get_achievement(&env, achievement_id)?;
+        if achievement.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+
+        let key = DataKey::Approval(achievement_id);
+        env.storage().persistent().set(&key, &Approval { spender, expires_at });
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        Ok(())
+    }
+
+    /// Revokes any outstanding approval on an achievement.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `owner` - The current owner of the achievement; must authenticate.
+    /// * `achievement_id` - The ID of the achievement whose approval is revoked.
+    pub fn revoke_approval(env: Env, owner: Address, achievement_id: u32) -> Result<(), Error> {
+        owner.require_auth();
+
+        let achievement = Self::get_achievement(&env, achievement_id)?;
+        if achievement.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Approval(achievement_id));
+        Ok(())
+    }
+
+    /// Revokes an issued achievement, recording the reason and timestamp.
+    ///
+    /// `issuer` must be the admin or the issuer who minted the achievement.
+    /// A revoked achievement still exists on-chain for auditability, but
+    /// `verify` reports it as invalid and `status` reports it as `Revoked`.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `issuer` - The admin or the achievement's original issuer; must authenticate.
+    /// * `achievement_id` - The ID of the achievement to revoke.
+    /// * `reason` - An application-defined revocation reason code.
+    pub fn revoke(env: Env, issuer: Address, achievement_id: u32, reason: u32) -> Result<(), Error> {
+        issuer.require_auth();
+        let achievement = Self::get_achievement(&env, achievement_id)?;
+        if achievement.issuer != issuer && Self::require_admin(&env, &issuer).is_err() {
+            return Err(Error::Unauthorized);
+        }
+        let timestamp = env.ledger().timestamp();
+
+        let key = DataKey::Revocation(achievement_id);
+        env.storage().persistent().set(&key, &Revocation { timestamp, reason });
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+        env.events().publish(
+            (Symbol::new(&env, "achievement"), Symbol::new(&env, "revoked")),
+            (achievement_id, reason, timestamp),
+        );
+
+        Ok(())
+    }
+
+    /// Returns whether an achievement has been revoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `achievement_id` - The ID of the achievement to check.
+    pub fn is_revoked(env: Env, achievement_id: u32) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Revocation(achievement_id))
+    }
+
+    /// Returns the lifecycle status of an achievement.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `achievement_id` - The ID of the achievement to check.
+    pub fn status(env: Env, achievement_id: u32) -> AchievementStatus {
+        if Self::get_achievement(&env, achievement_id).is_err() {
+            return AchievementStatus::Unknown;
+        }
+        if Self::is_revoked(env, achievement_id) {
+            AchievementStatus::Revoked
+        } else {
+            AchievementStatus::Active
+        }
+    }
+
+    /// Verifies if a user has a specific, non-revoked achievement.
+    ///
+    /// Performs a direct keyed lookup rather than scanning every achievement.
     ///
     /// # Arguments
     ///
@@ -52,32 +361,219 @@ impl CourseAchievementsContract {
     /// * `achievement_id` - The ID of the achievement to verify.
     /// * `user_id` - The ID of the user to verify against.
     pub fn verify(env: Env, achievement_id: u32, user_id: u32) -> Result<bool, Error> {
-        let achievements: Vec<Achievement> = env.storage().instance().get(&symbol_short!("achievements")).unwrap_or(Vec::new(&env));
-
-        for achievement in achievements.iter() {
-            if achievement.id == achievement_id && achievement.user_id == user_id {
-                return Ok(true);
-            }
+        let achievement = match Self::get_achievement(&env, achievement_id) {
+            Ok(achievement) => achievement,
+            Err(_) => return Ok(false),
+        };
+        if achievement.user_id != user_id {
+            return Ok(false);
+        }
+        if Self::is_revoked(env, achievement_id) {
+            return Ok(false);
         }
-        Ok(false)
+        Ok(true)
     }
 
-    /// Retrieves all achievements for a given user.
+    /// Retrieves all achievements for a given user via the user secondary index.
     ///
     /// # Arguments
     ///
     /// * `env` - The contract environment.
     /// * `user_id` - The ID of the user.
     pub fn get_user_achievements(env: Env, user_id: u32) -> Result<Vec<Achievement>, Error> {
-        let achievements: Vec<Achievement> = env.storage().instance().get(&symbol_short!("achievements")).unwrap_or(Vec::new(&env));
-        let mut user_achievements = Vec::new(&env);
+        let ids = Self::load_index(&env, DataKey::UserIndex(user_id));
+        Self::hydrate(&env, &ids)
+    }
+
+    /// Retrieves a page of achievements for a user, ordered by ascending id.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `user_id` - The ID of the user.
+    /// * `start_after` - Return only achievements with an id strictly greater than this, if set.
+    /// * `limit` - The maximum number of achievements to return.
+    pub fn get_user_achievements_paged(
+        env: Env,
+        user_id: u32,
+        start_after: Option<u32>,
+        limit: u32,
+    ) -> Result<Vec<Achievement>, Error> {
+        let ids = Self::load_index(&env, DataKey::UserIndex(user_id));
+        let page = Self::page_ids(&env, &ids, start_after, limit);
+        Self::hydrate(&env, &page)
+    }
+
+    /// Retrieves a page of achievements issued for a course, ordered by ascending id.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    /// * `course_id` - The ID of the course.
+    /// * `start_after` - Return only achievements with an id strictly greater than this, if set.
+    /// * `limit` - The maximum number of achievements to return.
+    pub fn get_course_achievements(
+        env: Env,
+        course_id: u32,
+        start_after: Option<u32>,
+        limit: u32,
+    ) -> Result<Vec<Achievement>, Error> {
+        let ids = Self::load_index(&env, DataKey::CourseIndex(course_id));
+        let page = Self::page_ids(&env, &ids, start_after, limit);
+        Self::hydrate(&env, &page)
+    }
+
+    /// Returns the total number of achievements ever issued.
+    ///
+    /// # Arguments
+    ///
+    /// * `env` - The contract environment.
+    pub fn total_issued(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::NextId).unwrap_or(1) - 1
+    }
 
-        for achievement in achievements.iter() {
-            if achievement.user_id == user_id {
-                user_achievements.push_back(achievement.clone());
+    fn page_ids(env: &Env, ids: &Vec<u32>, start_after: Option<u32>, limit: u32) -> Vec<u32> {
+        let mut page = Vec::new(env);
+        for id in ids.iter() {
+            if page.len() >= limit {
+                break;
             }
+            if let Some(cursor) = start_after {
+                if id <= cursor {
+                    continue;
+                }
+            }
+            page.push_back(id);
         }
-        Ok(user_achievements)
+        page
+    }
+
+    fn hydrate(env: &Env, ids: &Vec<u32>) -> Result<Vec<Achievement>, Error> {
+        let mut achievements = Vec::new(env);
+        for id in ids.iter() {
+            if let Ok(achievement) = Self::get_achievement(env, id) {
+                achievements.push_back(achievement);
+            }
+        }
+        Ok(achievements)
+    }
+
+    fn next_id(env: &Env) -> u32 {
+        let id: u32 = env.storage().instance().get(&DataKey::NextId).unwrap_or(1);
+        env.storage().instance().set(&DataKey::NextId, &(id + 1));
+        id
+    }
+
+    fn put_achievement(env: &Env, achievement: &Achievement) {
+        let key = DataKey::Achievement(achievement.id);
+        env.storage().persistent().set(&key, achievement);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+
+    fn get_achievement(env: &Env, achievement_id: u32) -> Result<Achievement, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Achievement(achievement_id))
+            .ok_or(Error::NotFound)
+    }
+
+    fn load_index(env: &Env, key: DataKey) -> Vec<u32> {
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn index_push(env: &Env, key: DataKey, id: u32) {
+        let mut ids = Self::load_index(env, key.clone());
+        ids.push_back(id);
+        env.storage().persistent().set(&key, &ids);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+
+    /// Inserts `id` into the index at `key`, keeping it sorted in ascending
+    /// order. Used where an id already assigned elsewhere (e.g. a
+    /// transferred achievement) may be smaller than ids already present,
+    /// unlike `index_push`'s append which relies on `id` being the newest
+    /// (and therefore largest) one ever assigned.
+    fn index_insert_sorted(env: &Env, key: DataKey, id: u32) {
+        let ids = Self::load_index(env, key.clone());
+        let mut result = Vec::new(env);
+        let mut inserted = false;
+        for existing in ids.iter() {
+            if !inserted && id < existing {
+                result.push_back(id);
+                inserted = true;
+            }
+            result.push_back(existing);
+        }
+        if !inserted {
+            result.push_back(id);
+        }
+        env.storage().persistent().set(&key, &result);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+
+    fn index_remove(env: &Env, key: DataKey, id: u32) {
+        let ids = Self::load_index(env, key.clone());
+        let mut remaining = Vec::new(env);
+        for existing in ids.iter() {
+            if existing != id {
+                remaining.push_back(existing);
+            }
+        }
+        env.storage().persistent().set(&key, &remaining);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+
+    fn is_owner_or_approved(env: &Env, achievement: &Achievement, caller: &Address) -> bool {
+        if &achievement.owner == caller {
+            return true;
+        }
+        if let Some(approval) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Approval>(&DataKey::Approval(achievement.id))
+        {
+            return &approval.spender == caller && approval.expires_at > env.ledger().timestamp();
+        }
+        false
+    }
+
+    fn issuers(env: &Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Issuers)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn is_authorized_issuer(env: &Env, issuer: &Address) -> bool {
+        if let Some(admin) = env.storage().instance().get::<DataKey, Address>(&DataKey::Admin) {
+            if &admin == issuer {
+                return true;
+            }
+        }
+        Self::issuers(env).contains(issuer)
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::Unauthorized)?;
+        if &admin != caller {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
     }
 }
 
@@ -86,4 +582,5 @@ impl CourseAchievementsContract {
 pub enum Error {
     NotFound = 1,
     Unauthorized = 2,
-}
\ No newline at end of file
+    AlreadyInitialized = 3,
+}
@@ -1,43 +1,147 @@
 #![cfg(test)]
 
-use super::{CourseAchievementsContract, CourseAchievementsContractClient, Achievement, Error};
-use soroban_sdk::{Env, Symbol, Vec};
+use super::{
+    AchievementStatus, CourseAchievementsContract, CourseAchievementsContractClient, Error,
+};
+use soroban_sdk::testutils::{Address as _, Events as _, Ledger as _};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
+
+fn setup(env: &Env) -> (CourseAchievementsContractClient, Address) {
+    let contract_id = env.register_contract(None, CourseAchievementsContract);
+    let client = CourseAchievementsContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (client, admin)
+}
 
 #[test]
 fn test_issue_achievement() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, CourseAchievementsContract);
-    let client = CourseAchievementsContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
 
     let course_id = 101;
     let user_id = 1;
+    let owner = Address::generate(&env);
     let metadata_uri = Symbol::new(&env, "ipfs://QmW");
 
-    let result = client.issue(&course_id, &user_id, &metadata_uri);
+    let result = client.issue(&admin, &course_id, &user_id, &owner, &metadata_uri, &false);
     assert!(result.is_ok());
 
     let achievement = result.unwrap();
     assert_eq!(achievement.id, 1);
     assert_eq!(achievement.course_id, course_id);
     assert_eq!(achievement.user_id, user_id);
+    assert_eq!(achievement.owner, owner);
     assert_eq!(achievement.metadata_uri, metadata_uri);
+    assert!(!achievement.soulbound);
 
     let achievements = client.get_user_achievements(&user_id).unwrap();
     assert_eq!(achievements.len(), 1);
     assert_eq!(achievements.get(0).unwrap().id, 1);
 }
 
+#[test]
+fn test_issue_emits_achievement_issued_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let metadata_uri = Symbol::new(&env, "ipfs://QmW");
+    let achievement = client
+        .issue(&admin, &101, &1, &owner, &metadata_uri, &false)
+        .unwrap();
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "achievement"), Symbol::new(&env, "issued")).into_val(&env),
+                (achievement.id, 101u32, 1u32, achievement.issued_at, metadata_uri).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_issue_requires_authorized_issuer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup(&env);
+
+    let stranger = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let result = client.try_issue(
+        &stranger,
+        &101,
+        &1,
+        &owner,
+        &Symbol::new(&env, "ipfs://QmW"),
+        &false,
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_add_issuer_allows_minting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let issuer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    client.add_issuer(&admin, &issuer);
+
+    let result = client.issue(
+        &issuer,
+        &101,
+        &1,
+        &owner,
+        &Symbol::new(&env, "ipfs://QmW"),
+        &false,
+    );
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_remove_issuer_revokes_minting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let issuer = Address::generate(&env);
+    let owner = Address::generate(&env);
+    client.add_issuer(&admin, &issuer);
+    client.remove_issuer(&admin, &issuer);
+
+    let result = client.try_issue(
+        &issuer,
+        &101,
+        &1,
+        &owner,
+        &Symbol::new(&env, "ipfs://QmW"),
+        &false,
+    );
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
 #[test]
 fn test_verify_achievement() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, CourseAchievementsContract);
-    let client = CourseAchievementsContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
 
     let course_id = 101;
     let user_id = 1;
+    let owner = Address::generate(&env);
     let metadata_uri = Symbol::new(&env, "ipfs://QmW");
 
-    let issued_achievement = client.issue(&course_id, &user_id, &metadata_uri).unwrap();
+    let issued_achievement = client
+        .issue(&admin, &course_id, &user_id, &owner, &metadata_uri, &false)
+        .unwrap();
 
     let is_verified = client.verify(&issued_achievement.id, &user_id).unwrap();
     assert!(is_verified);
@@ -52,18 +156,47 @@ fn test_verify_achievement() {
 #[test]
 fn test_get_user_achievements() {
     let env = Env::default();
-    let contract_id = env.register_contract(None, CourseAchievementsContract);
-    let client = CourseAchievementsContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
 
     let user1_id = 1;
     let user2_id = 2;
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
 
     // Issue achievements for user 1
-    client.issue(&101, &user1_id, &Symbol::new(&env, "ipfs://QmW1")).unwrap();
-    client.issue(&102, &user1_id, &Symbol::new(&env, "ipfs://QmW2")).unwrap();
+    client
+        .issue(
+            &admin,
+            &101,
+            &user1_id,
+            &owner1,
+            &Symbol::new(&env, "ipfs://QmW1"),
+            &false,
+        )
+        .unwrap();
+    client
+        .issue(
+            &admin,
+            &102,
+            &user1_id,
+            &owner1,
+            &Symbol::new(&env, "ipfs://QmW2"),
+            &false,
+        )
+        .unwrap();
 
     // Issue an achievement for user 2
-    client.issue(&201, &user2_id, &Symbol::new(&env, "ipfs://QmW3")).unwrap();
+    client
+        .issue(
+            &admin,
+            &201,
+            &user2_id,
+            &owner2,
+            &Symbol::new(&env, "ipfs://QmW3"),
+            &false,
+        )
+        .unwrap();
 
     let user1_achievements = client.get_user_achievements(&user1_id).unwrap();
     assert_eq!(user1_achievements.len(), 2);
@@ -76,4 +209,444 @@ fn test_get_user_achievements() {
 
     let user3_achievements = client.get_user_achievements(&3).unwrap();
     assert_eq!(user3_achievements.len(), 0);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_transfer_moves_ownership() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    client.issue(
+        &admin,
+        &1,
+        &2,
+        &owner2,
+        &Symbol::new(&env, "ipfs://QmW2"),
+        &false,
+    );
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner1,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        )
+        .unwrap();
+
+    client.transfer(&owner1, &owner2, &2, &achievement.id);
+
+    let achievements = client.get_user_achievements(&2).unwrap();
+    assert!(achievements.iter().any(|a| a.id == achievement.id && a.owner == owner2));
+}
+
+#[test]
+fn test_transfer_emits_achievement_transferred_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner1,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        )
+        .unwrap();
+
+    client.transfer(&owner1, &owner2, &2, &achievement.id);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "achievement"), Symbol::new(&env, "issued")).into_val(&env),
+                (
+                    achievement.id,
+                    101u32,
+                    1u32,
+                    achievement.issued_at,
+                    achievement.metadata_uri.clone(),
+                )
+                    .into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "achievement"), Symbol::new(&env, "transferred")).into_val(&env),
+                (achievement.id, owner1.clone(), 2u32).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_transfer_fails_for_soulbound_achievement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    client.issue(
+        &admin,
+        &1,
+        &2,
+        &owner2,
+        &Symbol::new(&env, "ipfs://QmW2"),
+        &false,
+    );
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner1,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &true,
+        )
+        .unwrap();
+
+    let result = client.try_transfer(&owner1, &owner2, &2, &achievement.id);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_approved_spender_can_transfer_until_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.issue(
+        &admin,
+        &1,
+        &2,
+        &owner2,
+        &Symbol::new(&env, "ipfs://QmW2"),
+        &false,
+    );
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner1,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        )
+        .unwrap();
+
+    env.ledger().set_timestamp(100);
+    client.approve(&owner1, &spender, &achievement.id, &200);
+
+    client.transfer(&spender, &owner2, &2, &achievement.id);
+    let achievements = client.get_user_achievements(&2).unwrap();
+    assert!(achievements.iter().any(|a| a.id == achievement.id && a.owner == owner2));
+}
+
+#[test]
+fn test_revoked_approval_cannot_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.issue(
+        &admin,
+        &1,
+        &2,
+        &owner2,
+        &Symbol::new(&env, "ipfs://QmW2"),
+        &false,
+    );
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner1,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        )
+        .unwrap();
+
+    client.approve(&owner1, &spender, &achievement.id, &200);
+    client.revoke_approval(&owner1, &achievement.id);
+
+    let result = client.try_transfer(&spender, &owner2, &2, &achievement.id);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_revoke_achievement_fails_verification() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        )
+        .unwrap();
+
+    assert_eq!(client.status(&achievement.id), AchievementStatus::Active);
+    assert!(!client.is_revoked(&achievement.id));
+
+    client.revoke(&admin, &achievement.id, &1);
+
+    assert_eq!(client.status(&achievement.id), AchievementStatus::Revoked);
+    assert!(client.is_revoked(&achievement.id));
+    assert!(!client.verify(&achievement.id, &1).unwrap());
+}
+
+#[test]
+fn test_revoke_emits_achievement_revoked_event() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        )
+        .unwrap();
+
+    env.ledger().set_timestamp(500);
+    client.revoke(&admin, &achievement.id, &7);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "achievement"), Symbol::new(&env, "issued")).into_val(&env),
+                (
+                    achievement.id,
+                    101u32,
+                    1u32,
+                    achievement.issued_at,
+                    achievement.metadata_uri.clone(),
+                )
+                    .into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "achievement"), Symbol::new(&env, "revoked")).into_val(&env),
+                (achievement.id, 7u32, 500u64).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_status_unknown_for_missing_achievement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup(&env);
+
+    assert_eq!(client.status(&999), AchievementStatus::Unknown);
+}
+
+#[test]
+fn test_revoke_requires_authorized_issuer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        )
+        .unwrap();
+
+    let stranger = Address::generate(&env);
+    let result = client.try_revoke(&stranger, &achievement.id, &1);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_revoke_rejects_issuer_who_did_not_mint_it() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner = Address::generate(&env);
+    let achievement = client
+        .issue(
+            &admin,
+            &101,
+            &1,
+            &owner,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        )
+        .unwrap();
+
+    // A different accredited issuer, authorized globally but not the
+    // original minter of this achievement, must not be able to revoke it.
+    let other_issuer = Address::generate(&env);
+    client.add_issuer(&admin, &other_issuer);
+
+    let result = client.try_revoke(&other_issuer, &achievement.id, &1);
+    assert_eq!(result, Err(Ok(Error::Unauthorized)));
+
+    // The original issuer can still revoke it.
+    client.revoke(&admin, &achievement.id, &1);
+    assert_eq!(client.status(&achievement.id), AchievementStatus::Revoked);
+}
+
+#[test]
+fn test_get_user_achievements_paged() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner = Address::generate(&env);
+    for i in 0..5u32 {
+        client.issue(
+            &admin,
+            &101,
+            &1,
+            &owner,
+            &Symbol::new(&env, "ipfs://QmW"),
+            &false,
+        );
+        let _ = i;
+    }
+
+    let first_page = client.get_user_achievements_paged(&1, &None, &2).unwrap();
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().id, 1);
+    assert_eq!(first_page.get(1).unwrap().id, 2);
+
+    let second_page = client
+        .get_user_achievements_paged(&1, &Some(2), &2)
+        .unwrap();
+    assert_eq!(second_page.len(), 2);
+    assert_eq!(second_page.get(0).unwrap().id, 3);
+    assert_eq!(second_page.get(1).unwrap().id, 4);
+
+    let last_page = client
+        .get_user_achievements_paged(&1, &Some(4), &2)
+        .unwrap();
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page.get(0).unwrap().id, 5);
+}
+
+#[test]
+fn test_paged_order_survives_out_of_order_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+
+    // owner2's user (id 2) already holds achievements 1 and 3 by the time
+    // it additionally receives achievement 2, whose id is smaller than 3.
+    let first = client
+        .issue(&admin, &101, &2, &owner2, &Symbol::new(&env, "ipfs://1"), &false)
+        .unwrap();
+    let to_transfer = client
+        .issue(&admin, &101, &1, &owner1, &Symbol::new(&env, "ipfs://2"), &false)
+        .unwrap();
+    let third = client
+        .issue(&admin, &101, &2, &owner2, &Symbol::new(&env, "ipfs://3"), &false)
+        .unwrap();
+    assert_eq!((first.id, to_transfer.id, third.id), (1, 2, 3));
+
+    client.transfer(&owner1, &owner2, &2, &to_transfer.id);
+
+    // Paging from the start should still see every id for user 2, in order,
+    // including the out-of-order transferred id 2.
+    let page = client
+        .get_user_achievements_paged(&2, &None, &10)
+        .unwrap();
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().id, 1);
+    assert_eq!(page.get(1).unwrap().id, 2);
+    assert_eq!(page.get(2).unwrap().id, 3);
+
+    // A cursor placed after id 1 must still surface the transferred id 2.
+    let page_after_1 = client
+        .get_user_achievements_paged(&2, &Some(1), &10)
+        .unwrap();
+    assert_eq!(page_after_1.len(), 2);
+    assert_eq!(page_after_1.get(0).unwrap().id, 2);
+    assert_eq!(page_after_1.get(1).unwrap().id, 3);
+}
+
+#[test]
+fn test_get_course_achievements_and_total_issued() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup(&env);
+
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    client.issue(
+        &admin,
+        &101,
+        &1,
+        &owner1,
+        &Symbol::new(&env, "ipfs://QmW1"),
+        &false,
+    );
+    client.issue(
+        &admin,
+        &101,
+        &2,
+        &owner2,
+        &Symbol::new(&env, "ipfs://QmW2"),
+        &false,
+    );
+    client.issue(
+        &admin,
+        &202,
+        &1,
+        &owner1,
+        &Symbol::new(&env, "ipfs://QmW3"),
+        &false,
+    );
+
+    let course_achievements = client.get_course_achievements(&101, &None, &10).unwrap();
+    assert_eq!(course_achievements.len(), 2);
+    assert_eq!(course_achievements.get(0).unwrap().course_id, 101);
+    assert_eq!(course_achievements.get(1).unwrap().course_id, 101);
+
+    assert_eq!(client.total_issued(), 3);
+}